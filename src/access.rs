@@ -0,0 +1,170 @@
+//! Per-operand read/write access and register def/use sets, borrowing
+//! bddisasm's per-operand access flags (used by its emulator example).
+//!
+//! Lets consumers build data-dependency graphs and do register liveness
+//! without re-deriving operand semantics for every mnemonic.
+
+use core::iter;
+
+use crate::{Instruction, Operand, Operation, Reg};
+
+/// Whether an operand is read, written, or both by its instruction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OpAccess {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl OpAccess {
+    fn is_read(self) -> bool {
+        matches!(self, OpAccess::Read | OpAccess::ReadWrite)
+    }
+
+    fn is_write(self) -> bool {
+        matches!(self, OpAccess::Write | OpAccess::ReadWrite)
+    }
+}
+
+impl Operand {
+    /// Returns how this operand, at position `n` of `insn`, is accessed.
+    ///
+    /// Pre/post-indexed memory operands write back their base register,
+    /// so their base is [`OpAccess::ReadWrite`] even on a load.
+    pub fn access(&self, insn: &Instruction, n: usize) -> OpAccess {
+        match self {
+            Operand::MemPreIdx { .. } | Operand::MemPostIdxImm { .. } | Operand::MemPostIdxReg { .. } => {
+                OpAccess::ReadWrite
+            }
+            _ => match insn.category() {
+                crate::InsnCategory::Load | crate::InsnCategory::Store => {
+                    if n < leading_dest_operands(insn.operation()) {
+                        OpAccess::Write
+                    } else {
+                        OpAccess::Read
+                    }
+                }
+                crate::InsnCategory::AtomicMemory => atomic_operand_access(insn.operation(), n),
+                _ => {
+                    if n == 0 && is_destination_operand(insn.operation()) {
+                        OpAccess::Write
+                    } else {
+                        OpAccess::Read
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// How many of an instruction's *leading* operands are destinations.
+///
+/// Most loads write a single destination register (operand 0); `LDP`-style
+/// pair loads write two. Plain stores write nothing (their operands are
+/// all sources/addressing), but the exclusive-store forms (`STXR`,
+/// `STLXR`) write their leading status-register operand.
+fn leading_dest_operands(op: Operation) -> usize {
+    use Operation::*;
+
+    match op {
+        LDP | LDPSW | LDNP => 2,
+        STXR | STLXR => 1,
+        LDR | LDRB | LDRH | LDRSB | LDRSH | LDRSW | LDUR | LDURB | LDURH | LDURSB | LDURSH
+        | LDURSW | LDAR | LDAXR | LDXR => 1,
+        _ => 0,
+    }
+}
+
+/// Access for operand `n` of an atomic read-modify-write instruction.
+///
+/// `LDADD`-family ops (`Rs, Rt, [Rn]`) read `Rs` as the operand to add/set
+/// and write the previous memory value to `Rt`. `CAS`-family ops
+/// (`Rs, Rt, [Rn]`) instead read-write `Rs`: it carries the expected value
+/// in and the memory's prior value out, and `Rt`, the value stored on a
+/// match, is only read. `Rn`, the memory base, is read in
+/// both families (any base-register writeback is handled separately by
+/// the pre/post-indexed `Operand` arms above).
+fn atomic_operand_access(op: Operation, n: usize) -> OpAccess {
+    use Operation::*;
+
+    let is_cas = matches!(op, CAS | CASA | CASAL | CASL);
+
+    match n {
+        0 if is_cas => OpAccess::ReadWrite,
+        0 => OpAccess::Read,
+        1 if is_cas => OpAccess::Read,
+        1 => OpAccess::Write,
+        _ => OpAccess::Read,
+    }
+}
+
+/// Most two/three-operand ALU forms write their first operand and read
+/// the rest. Pure comparison/test forms (`CMP`, `CMN`, `TST`, ...) and
+/// branches have no destination operand at all.
+fn is_destination_operand(op: Operation) -> bool {
+    use Operation::*;
+
+    !matches!(
+        op,
+        CMP | CMN | TST | CCMP | CCMN | B | BL | BR | BLR | RET | CBZ | CBNZ | TBZ | TBNZ
+            | NOP | STR | STRB | STRH | STP | STUR | STURB | STURH
+    )
+}
+
+fn operand_reg(operand: &Operand) -> Option<Reg> {
+    match operand {
+        Operand::Reg { reg, .. } => Some(*reg),
+        Operand::MemReg(reg)
+        | Operand::MemOffset { reg, .. }
+        | Operand::MemPreIdx { reg, .. }
+        | Operand::MemPostIdxImm { reg, .. }
+        | Operand::MemPostIdxReg { reg, .. }
+        | Operand::MemExt { reg, .. } => Some(*reg),
+        _ => None,
+    }
+}
+
+impl Instruction {
+    /// Returns an iterator over the registers this instruction reads.
+    ///
+    /// Only explicit operands are considered, and a memory operand
+    /// contributes only its base register: index/offset registers and
+    /// implicitly read registers (such as `x30` for `ret`) are not reported.
+    pub fn regs_read(&self) -> impl Iterator<Item = Reg> + '_ {
+        let mut n = 0;
+        iter::from_fn(move || {
+            loop {
+                let operand = self.operand(n)?;
+                let idx = n;
+                n += 1;
+
+                if operand.access(self, idx).is_read() {
+                    if let Some(reg) = operand_reg(&operand) {
+                        return Some(reg);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Returns an iterator over the registers this instruction writes.
+    ///
+    /// Only explicit operands are considered: implicitly written registers
+    /// (such as `x30` for `bl`) are not reported.
+    pub fn regs_written(&self) -> impl Iterator<Item = Reg> + '_ {
+        let mut n = 0;
+        iter::from_fn(move || {
+            loop {
+                let operand = self.operand(n)?;
+                let idx = n;
+                n += 1;
+
+                if operand.access(self, idx).is_write() {
+                    if let Some(reg) = operand_reg(&operand) {
+                        return Some(reg);
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,52 @@
+//! Instruction category classification, following bddisasm's
+//! `instruction_category` API.
+
+use crate::{Instruction, Operation};
+
+/// A coarse grouping of what an instruction *does*, independent of its
+/// exact mnemonic.
+///
+/// Lets consumers filter a disassembly stream (count SIMD density, detect
+/// crypto usage, ...) without maintaining their own opcode tables.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum InsnCategory {
+    Branch,
+    Load,
+    Store,
+    Arithmetic,
+    Logical,
+    System,
+    Simd,
+    Crypto,
+    AtomicMemory,
+    Other,
+}
+
+impl Instruction {
+    /// Returns this instruction's [`InsnCategory`].
+    pub fn category(&self) -> InsnCategory {
+        use Operation::*;
+
+        match self.operation() {
+            B | BL | BR | BLR | CBZ | CBNZ | TBZ | TBNZ | RET => InsnCategory::Branch,
+            LDR | LDRB | LDRH | LDRSB | LDRSH | LDRSW | LDP | LDPSW | LDUR | LDURB | LDURH
+            | LDURSB | LDURSH | LDURSW | LDAR | LDAXR | LDXR | LDNP => InsnCategory::Load,
+            STR | STRB | STRH | STP | STUR | STURB | STURH | STLR | STLXR | STXR | STNP => {
+                InsnCategory::Store
+            }
+            LDADD | LDADDA | LDADDAL | LDADDL | LDCLR | LDEOR | LDSET | SWP | SWPA | SWPAL
+            | SWPL | CAS | CASA | CASAL | CASL => InsnCategory::AtomicMemory,
+            ADD | ADDS | SUB | SUBS | ADC | ADCS | SBC | SBCS | MUL | MADD | MSUB | MNEG
+            | UDIV | SDIV | CMP | CMN | NEG | NEGS => InsnCategory::Arithmetic,
+            AND | ANDS | ORR | ORN | EOR | EON | BIC | BICS | TST | MVN | MOV | MOVZ | MOVN
+            | MOVK | LSL | LSR | ASR | ROR => InsnCategory::Logical,
+            MSR | MRS | SVC | HVC | SMC | BRK | HLT | DMB | DSB | ISB | SYS | SYSL | HINT
+            | NOP | WFE | WFI | YIELD | SEV | SEVL => InsnCategory::System,
+            AESE | AESD | AESMC | AESIMC | SHA1C | SHA1H | SHA1M | SHA1P | SHA1SU0 | SHA1SU1
+            | SHA256H | SHA256H2 | SHA256SU0 | SHA256SU1 => InsnCategory::Crypto,
+            FADD | FSUB | FMUL | FDIV | FMOV | FCMP | FCVT | FABS | FNEG | FSQRT | FMLA
+            | FMLS | DUP | INS | UMOV | SMOV | TBL | TBX => InsnCategory::Simd,
+            _ => InsnCategory::Other,
+        }
+    }
+}
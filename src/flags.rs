@@ -0,0 +1,93 @@
+//! Condition-flag (NZCV) read/write sets, following bddisasm's `rflags`
+//! access reporting.
+
+use core::ops::{BitOr, BitOrAssign};
+
+use crate::{Instruction, Operation};
+
+/// A set of AArch64 condition flags (`N`, `Z`, `C`, `V`).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Flags(u8);
+
+impl Flags {
+    pub const NONE: Flags = Flags(0);
+    pub const N: Flags = Flags(1 << 0);
+    pub const Z: Flags = Flags(1 << 1);
+    pub const C: Flags = Flags(1 << 2);
+    pub const V: Flags = Flags(1 << 3);
+    pub const NZCV: Flags = Flags(Self::N.0 | Self::Z.0 | Self::C.0 | Self::V.0);
+
+    /// Returns `true` if `self` contains every flag in `other`.
+    pub fn contains(self, other: Flags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no flags are set.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for Flags {
+    type Output = Flags;
+
+    fn bitor(self, rhs: Flags) -> Flags {
+        Flags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Flags {
+    fn bitor_assign(&mut self, rhs: Flags) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// The subset of NZCV a [`crate::Operation`]'s condition code consumes.
+///
+/// All of `AL`/`NV` read nothing; everything else reads at least one
+/// flag.
+fn cond_reads(cond: crate::operand::Cond) -> Flags {
+    use crate::operand::Cond::*;
+
+    match cond {
+        EQ | NE => Flags::Z,
+        CS | CC => Flags::C,
+        MI | PL => Flags::N,
+        VS | VC => Flags::V,
+        HI | LS => Flags::C | Flags::Z,
+        GE | LT => Flags::N | Flags::V,
+        GT | LE => Flags::N | Flags::Z | Flags::V,
+        AL | NV => Flags::NONE,
+    }
+}
+
+impl Instruction {
+    /// Returns the condition flags this instruction reads.
+    pub fn flags_read(&self) -> Flags {
+        use Operation::*;
+
+        match self.operation() {
+            ADCS | SBCS | ADC | SBC => Flags::C,
+            CCMP | CCMN | BCOND | CSEL | CSINC | CSINV | CSNEG | CSET | CSETM | CINC | CINV
+            | CNEG => self
+                .operands()
+                .find_map(|op| match op {
+                    crate::Operand::Cond(cond) => Some(cond_reads(cond)),
+                    _ => None,
+                })
+                .unwrap_or(Flags::NONE),
+            _ => Flags::NONE,
+        }
+    }
+
+    /// Returns the condition flags this instruction writes.
+    pub fn flags_written(&self) -> Flags {
+        use Operation::*;
+
+        match self.operation() {
+            ADDS | SUBS | ANDS | BICS | ADCS | SBCS | CMP | CMN | TST | NEGS => Flags::NZCV,
+            CCMP | CCMN => Flags::NZCV,
+            _ => Flags::NONE,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! [`core::fmt::Display`] impls that reproduce the canonical Binja textual
+//! syntax, e.g. `"add x0, x1, #0x41"` or `"ldr x0, [sp], #0x10"`.
+//!
+//! These exist so that callers can pretty-print a decoded stream without
+//! re-implementing operand formatting themselves; [`Instruction::mnem`]
+//! alone only gives the opcode name.
+
+use core::fmt;
+
+use crate::{Imm, Instruction, Operand, Reg, Shift, SysReg};
+
+/// Writes `value`'s `Debug` form lowercased.
+///
+/// `Reg` and `SysReg` variant names already match Binja's register/sysreg
+/// names up to case (`X0` -> `x0`, `MIDR_EL1` -> `midr_el1`), so this
+/// avoids hand-listing every variant just to change its case. Written
+/// against a `fmt::Write` shim since this crate is `no_std` and has no
+/// `alloc::format!` available.
+fn write_lower_debug(f: &mut fmt::Formatter<'_>, value: &impl fmt::Debug) -> fmt::Result {
+    struct Lower<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+    impl fmt::Write for Lower<'_, '_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            for c in s.chars() {
+                self.0.write_char(c.to_ascii_lowercase())?;
+            }
+            Ok(())
+        }
+    }
+
+    use fmt::Write;
+    write!(Lower(f), "{:?}", value)
+}
+
+impl fmt::Display for Imm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.neg {
+            write!(f, "#-0x{:x}", self.val)
+        } else {
+            write!(f, "#0x{:x}", self.val)
+        }
+    }
+}
+
+impl fmt::Display for Reg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_lower_debug(f, self)
+    }
+}
+
+impl fmt::Display for SysReg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_lower_debug(f, self)
+    }
+}
+
+impl fmt::Display for Shift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shift::LSL(0) | Shift::LSR(0) | Shift::ASR(0) | Shift::ROR(0) => Ok(()),
+            Shift::LSL(amt) => write!(f, " lsl #{}", amt),
+            Shift::LSR(amt) => write!(f, " lsr #{}", amt),
+            Shift::ASR(amt) => write!(f, " asr #{}", amt),
+            Shift::ROR(amt) => write!(f, " ror #{}", amt),
+            Shift::UXTB(0) => write!(f, " uxtb"),
+            Shift::UXTB(amt) => write!(f, " uxtb #{}", amt),
+            Shift::UXTH(0) => write!(f, " uxth"),
+            Shift::UXTH(amt) => write!(f, " uxth #{}", amt),
+            Shift::UXTW(0) => write!(f, " uxtw"),
+            Shift::UXTW(amt) => write!(f, " uxtw #{}", amt),
+            Shift::UXTX(0) => write!(f, " uxtx"),
+            Shift::UXTX(amt) => write!(f, " uxtx #{}", amt),
+            Shift::SXTB(0) => write!(f, " sxtb"),
+            Shift::SXTB(amt) => write!(f, " sxtb #{}", amt),
+            Shift::SXTH(0) => write!(f, " sxth"),
+            Shift::SXTH(amt) => write!(f, " sxth #{}", amt),
+            Shift::SXTW(0) => write!(f, " sxtw"),
+            Shift::SXTW(amt) => write!(f, " sxtw #{}", amt),
+            Shift::SXTX(0) => write!(f, " sxtx"),
+            Shift::SXTX(amt) => write!(f, " sxtx #{}", amt),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Reg { reg, shift } => {
+                write!(f, "{}", reg)?;
+                if let Some(shift) = shift {
+                    write!(f, "{}", shift)?;
+                }
+                Ok(())
+            }
+            Operand::Imm32 { imm, shift } | Operand::Imm64 { imm, shift } => {
+                write!(f, "{}", imm)?;
+                if let Some(shift) = shift {
+                    write!(f, "{}", shift)?;
+                }
+                Ok(())
+            }
+            Operand::MemReg(reg) => write!(f, "[{}]", reg),
+            Operand::MemOffset { reg, offset, .. } => {
+                if *offset == 0 {
+                    write!(f, "[{}]", reg)
+                } else if *offset < 0 {
+                    write!(f, "[{}, #-0x{:x}]", reg, -offset)
+                } else {
+                    write!(f, "[{}, #0x{:x}]", reg, offset)
+                }
+            }
+            Operand::MemPreIdx { reg, offset } => {
+                if *offset < 0 {
+                    write!(f, "[{}, #-0x{:x}]!", reg, -offset)
+                } else {
+                    write!(f, "[{}, #0x{:x}]!", reg, offset)
+                }
+            }
+            Operand::MemPostIdxImm { reg, imm } => write!(f, "[{}], {}", reg, imm),
+            Operand::MemPostIdxReg { reg, offset } => write!(f, "[{}], {}", reg, offset),
+            Operand::MemExt { reg, offset, shift } => {
+                write!(f, "[{}, {}", reg, offset)?;
+                if let Some(shift) = shift {
+                    write!(f, "{}", shift)?;
+                }
+                write!(f, "]")
+            }
+            Operand::Label(imm) => write!(f, "{}", imm),
+            Operand::SysReg(sysreg) => write!(f, "{}", sysreg),
+            Operand::Cond(cond) => write_lower_debug(f, cond),
+            // Other operand kinds (multi-register lists, floating-point
+            // immediates, ...) fall back to `Debug` until they get their
+            // own rendering.
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/// Formats the full textual form of a decoded instruction, e.g.
+/// `"add x0, x1, #0x41"`.
+///
+/// # Example
+/// ```
+/// use bad64::decode;
+///
+/// // add x0, x1, #0x41 - "\x20\x04\x01\x91"
+/// let decoded = decode(0x91010420, 0x1000).unwrap();
+/// assert_eq!(decoded.to_string(), "add x0, x1, #0x41");
+/// ```
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnem())?;
+
+        for (n, operand) in self.operands().enumerate() {
+            if n == 0 {
+                write!(f, " {}", operand)?;
+            } else {
+                write!(f, ", {}", operand)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+//! ISA feature-set classification, following bddisasm's `isa_set` API.
+
+use crate::{Instruction, Operation};
+
+/// The architecture extension an instruction belongs to.
+///
+/// Lets consumers gate on required CPU features (e.g. refuse to run a
+/// disassembly that uses `CryptoAes` or `Sve` extensions the target doesn't
+/// implement) without maintaining their own opcode tables.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum IsaFeature {
+    /// Base A64 integer instruction set.
+    Base,
+    /// Scalar/SIMD floating point.
+    Fp,
+    /// Advanced SIMD (NEON).
+    Simd,
+    /// Scalable Vector Extension.
+    Sve,
+    /// Large System Extensions / atomic read-modify-write memory ops.
+    Atomics,
+    /// Pointer authentication.
+    PAuth,
+    /// Memory Tagging Extension.
+    Mte,
+    /// AES instructions.
+    CryptoAes,
+    /// SHA1/SHA2 instructions.
+    CryptoSha,
+}
+
+impl Instruction {
+    /// Returns the [`IsaFeature`] this instruction requires.
+    pub fn isa_feature(&self) -> IsaFeature {
+        use Operation::*;
+
+        match self.operation() {
+            PTRUE | PFALSE | PTEST | WHILELT | WHILELE | WHILELO | WHILELS | LD1B | LD1H
+            | LD1W | LD1D | ST1B | ST1H | ST1W | ST1D | CNTB | CNTH | CNTW | CNTD => {
+                IsaFeature::Sve
+            }
+            AESE | AESD | AESMC | AESIMC => IsaFeature::CryptoAes,
+            SHA1C | SHA1H | SHA1M | SHA1P | SHA1SU0 | SHA1SU1 | SHA256H | SHA256H2
+            | SHA256SU0 | SHA256SU1 => IsaFeature::CryptoSha,
+            LDADD | LDADDA | LDADDAL | LDADDL | LDCLR | LDEOR | LDSET | SWP | SWPA | SWPAL
+            | SWPL | CAS | CASA | CASAL | CASL => IsaFeature::Atomics,
+            PACIA | PACIB | PACDA | PACDB | PACGA | AUTIA | AUTIB | AUTDA | AUTDB | RETAA
+            | RETAB | BRAA | BRAB | BLRAA | BLRAB => IsaFeature::PAuth,
+            STG | STZG | ST2G | STZ2G | LDG | IRG | GMI | SUBP | SUBPS => IsaFeature::Mte,
+            FADD | FSUB | FMUL | FDIV | FMOV | FCMP | FCVT | FABS | FNEG | FSQRT | FMLA
+            | FMLS => IsaFeature::Fp,
+            DUP | INS | UMOV | SMOV | TBL | TBX | ADDV | SADDLV | UADDLV => IsaFeature::Simd,
+            _ => IsaFeature::Base,
+        }
+    }
+}
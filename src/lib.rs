@@ -72,17 +72,32 @@ use num_traits::FromPrimitive;
 
 use bad64_sys::*;
 
+mod access;
+mod category;
+mod encode;
+mod flags;
+mod fmt;
+mod isa;
 mod operand;
 mod operation;
 mod reg;
 mod shift;
 mod sysreg;
+#[cfg(feature = "yaxpeax")]
+mod yaxpeax;
 
+pub use access::OpAccess;
+pub use category::InsnCategory;
+pub use encode::{assemble, encode, EncodeError};
+pub use flags::Flags;
+pub use isa::IsaFeature;
 pub use operand::{Imm, Operand};
 pub use operation::Operation;
 pub use reg::Reg;
 pub use shift::Shift;
 pub use sysreg::SysReg;
+#[cfg(feature = "yaxpeax")]
+pub use crate::yaxpeax::{ARMv8, InstDecoder};
 
 /// A decoded instruction
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -0,0 +1,70 @@
+//! [`yaxpeax_arch`] integration, enabled by the `yaxpeax` feature.
+//!
+//! This lets code that is generic over yaxpeax architectures (recursive
+//! descent disassemblers, analysis frameworks, ...) consume bad64 the same
+//! way it would consume yaxpeax-x86's `Decodable`/`LengthedInstruction`,
+//! without special-casing AArch64.
+
+use yaxpeax_arch::{AddressBase, Arch, Decoder as YaxpeaxDecoder, LengthedInstruction, Reader};
+
+use crate::{decode, DecodeError, Instruction, Operand};
+
+/// Zero-sized marker type for the AArch64 (ARMv8) architecture.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ARMv8;
+
+impl Arch for ARMv8 {
+    type Word = u8;
+    type Address = u64;
+    type Instruction = Instruction;
+    type DecodeError = DecodeError;
+    type Decoder = InstDecoder;
+    type Operand = Operand;
+}
+
+/// [`yaxpeax_arch::Decoder`] for AArch64, backed by [`crate::decode`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InstDecoder;
+
+impl YaxpeaxDecoder<ARMv8> for InstDecoder {
+    fn decode<T: Reader<<ARMv8 as Arch>::Address, <ARMv8 as Arch>::Word>>(
+        &self,
+        words: &mut T,
+    ) -> Result<Instruction, DecodeError> {
+        let address = words.total_offset();
+
+        let mut buf = [0u8; 4];
+        for b in buf.iter_mut() {
+            *b = words.next().ok_or(DecodeError::Short)?;
+        }
+
+        decode(u32::from_le_bytes(buf), address)
+    }
+}
+
+impl LengthedInstruction for Instruction {
+    type Unit = <ARMv8 as Arch>::Address;
+
+    /// AArch64 is fixed-width: every instruction is 4 bytes.
+    fn len(&self) -> Self::Unit {
+        Self::Unit::from_const(4)
+    }
+
+    fn min_size() -> Self::Unit {
+        Self::Unit::from_const(4)
+    }
+}
+
+impl yaxpeax_arch::DecodeError for DecodeError {
+    fn data_exhausted(&self) -> bool {
+        matches!(self, DecodeError::Short)
+    }
+
+    fn bad_opcode(&self) -> bool {
+        !matches!(self, DecodeError::Short)
+    }
+
+    fn bad_operand(&self) -> bool {
+        false
+    }
+}